@@ -1,13 +1,30 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use chrono::{DateTime, Duration, Utc};
+use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
 use tokio::task;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
 use crate::config::Config;
+use crate::eventsub::{EventSubMessage, NotificationPayload, Session, SessionPayload, StreamOfflineEvent, StreamOnlineEvent, SubscriptionCondition, SubscriptionRequest, SubscriptionTransport, UsersRes};
+use crate::irc::IrcAnnouncer;
+use crate::nats_sink::NatsSink;
 use crate::traits::EventHandler;
 
-/// Stores streamers who are currently streaming so that the event doesn't repeatedly trigger
-static mut C_STREAMING: Vec<String> = Vec::new();
+/// Where EventSub Notifications Are Delivered Over WebSocket
+const EVENTSUB_WS_URL: &str = "wss://eventsub.wss.twitch.tv/ws";
+
+/// Minimum Seconds Between `oauth2/validate` Calls, So Polling At a High Rate Doesn't Hammer Twitch
+const TOKEN_CHECK_INTERVAL_SECS: i64 = 300;
+
+/// Default Minimum Change In Viewer Count Before `on_update` Fires, So The Default Behavior Is
+/// "Fire On Title/Game Changes", Not "Fire On Every Single Poll"
+const DEFAULT_VIEWER_THRESHOLD: u32 = 25;
+
+/// Ceiling For The Exponential Backoff Between Failed EventSub (Re)Connect Attempts
+const MAX_RECONNECT_BACKOFF: tokio::time::Duration = tokio::time::Duration::from_secs(60);
 
 /// All Streamer info store in Config
 ///
@@ -45,7 +62,7 @@ pub(crate) struct StreamsRes {
 /// * `tags_ids` - IDs of the Tags Used
 /// * `tags` - Tags of the Stream
 /// * `is_mature` - Is the Stream Set As Mature
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StreamData {
     pub id: String,
     pub user_id: String,
@@ -74,6 +91,26 @@ pub(crate) struct Pagination {
     pub cursor: String
 }
 
+/// The Response From `oauth2/validate`
+///
+/// # Parameters
+/// * `expires_in` - Seconds Remaining Before The Token Expires
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct ValidateRes {
+    pub expires_in: u64
+}
+
+/// The Response From `oauth2/token` When Refreshing a Token
+///
+/// # Parameters
+/// * `access_token` - The New Token To Use Going Forward
+/// * `refresh_token` - The New Refresh Token To Use Going Forward
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct RefreshRes {
+    pub access_token: String,
+    pub refresh_token: String
+}
+
 
 /// Client For Running TwitchAlerts
 ///
@@ -84,14 +121,26 @@ pub(crate) struct Pagination {
 /// * `config` - The Config for the Client
 /// * `currently_streaming` - The streamers that are currently streaming
 /// * `delay` - Delay Between Check Cycles
+/// * `client_secret` - Twitch Client Secret, Used To Refresh an Expired Token
+/// * `refresh_token` - Twitch Refresh Token, Used To Obtain a New Token
+/// * `viewer_threshold` - Minimum Change In Viewer Count Before `on_update` Fires
+/// * `nats_sink` - Optional NATS JetStream Sink Alert Events Are Also Published To
+/// * `irc` - Optional IRC Announcer That Posts `on_stream` Alerts Into Twitch Chat
+/// * `last_token_check` - When `oauth2/validate` Was Last Called, Used To Throttle It
 #[derive(Clone)]
 pub struct Client {
     pub client_id: String,
     pub token: String,
     event_handler: Option<Arc<dyn EventHandler>>,
     config: Config,
-    currently_streaming: Vec<String>,
-    delay: tokio::time::Duration
+    currently_streaming: Arc<Mutex<HashMap<String, StreamData>>>,
+    delay: tokio::time::Duration,
+    client_secret: Option<String>,
+    refresh_token: Option<String>,
+    viewer_threshold: u32,
+    nats_sink: Option<Arc<NatsSink>>,
+    irc: Option<Arc<IrcAnnouncer>>,
+    last_token_check: Option<DateTime<Utc>>
 }
 
 impl Client {
@@ -130,8 +179,14 @@ impl Client {
             token: c.token.clone().unwrap(),
             event_handler: None,
             config: c.clone(),
-            currently_streaming: Vec::new(),
-            delay: tokio::time::Duration::from_millis(d)
+            currently_streaming: Arc::new(Mutex::new(HashMap::new())),
+            delay: tokio::time::Duration::from_millis(d),
+            client_secret: c.client_secret.clone(),
+            refresh_token: c.refresh_token.clone(),
+            viewer_threshold: DEFAULT_VIEWER_THRESHOLD,
+            nats_sink: None,
+            irc: None,
+            last_token_check: None
         }
     }
 
@@ -168,6 +223,82 @@ impl Client {
         self
     }
 
+    /// Used to set the minimum change in `viewer_count` required for `on_update` to fire.
+    /// Title and game changes always fire `on_update` regardless of this threshold.
+    ///
+    /// # Arguments
+    /// * `self` - Requires a Client To Run The Function
+    /// * `viewer_threshold` - The Minimum Viewer Count Change Required To Trigger `on_update`
+    ///
+    /// # Example
+    /// ```
+    /// use twitchalerts::client::Client;
+    ///
+    /// async fn main() {
+    ///     let client: Client = Client::new().await.viewer_threshold(50);
+    /// }
+    /// ```
+    pub fn viewer_threshold(mut self, viewer_threshold: u32) -> Self {
+        self.viewer_threshold = viewer_threshold;
+
+        self
+    }
+
+    /// Used to add a NATS JetStream sink that publishes every `on_stream`/`on_offline` event
+    /// to `subject` as JSON, independent of and in addition to the `EventHandler`. This lets
+    /// downstream services receive durable, replayable alert delivery.
+    ///
+    /// # Arguments
+    /// * `self` - Requires a Client To Run The Function
+    /// * `url` - The NATS Server URL To Connect To
+    /// * `subject` - The JetStream Subject To Publish Alert Events To
+    ///
+    /// # Example
+    /// ```no_run
+    /// use twitchalerts::client::Client;
+    ///
+    /// async fn main() -> Result<(), twitchalerts::error::Error> {
+    ///     let client: Client = Client::new().await.nats_sink("nats://localhost:4222", "twitchalerts.events").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn nats_sink(mut self, url: &str, subject: &str) -> Result<Self, crate::error::Error> {
+        self.nats_sink = Some(Arc::new(NatsSink::connect(url, subject).await?));
+
+        Ok(self)
+    }
+
+    /// Used to add an IRC announcer that posts a templated PRIVMSG into Twitch chat whenever
+    /// `on_stream` fires, using the `irc_nick`, `irc_channels` and `irc_message_template` fields
+    /// configured on `Config`. Reconnects on disconnect and answers `PING` with `PONG`.
+    ///
+    /// # Arguments
+    /// * `self` - Requires a Client To Run The Function
+    ///
+    /// # Example
+    /// ```no_run
+    /// use twitchalerts::client::Client;
+    ///
+    /// async fn main() -> Result<(), twitchalerts::error::Error> {
+    ///     let client: Client = Client::new().await.irc_announcer().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn irc_announcer(mut self) -> Result<Self, crate::error::Error> {
+        let nick = self.config.irc_nick.clone()
+            .ok_or_else(|| crate::error::Error::new("No irc_nick configured, cannot start the IRC announcer...", 22u16))?;
+        let channels = self.config.irc_channels.clone()
+            .ok_or_else(|| crate::error::Error::new("No irc_channels configured, cannot start the IRC announcer...", 22u16))?;
+        let template = self.config.irc_message_template.clone()
+            .unwrap_or_else(|| "{name} just went live: {title}".to_string());
+
+        self.irc = Some(Arc::new(IrcAnnouncer::connect(self.token.clone(), nick, channels, template)));
+
+        Ok(self)
+    }
+
     /// Used to start running the TwitchAlerts Client
     ///
     ///  # Arguments
@@ -199,22 +330,26 @@ impl Client {
     ///     Ok(())
     /// }
     ///```
-    pub async fn run(self) -> Result<(), crate::error::Error> {
+    pub async fn run(mut self) -> Result<(), crate::error::Error> {
         if self.event_handler.is_none() {
             panic!("No Event Handler Set");
         }
 
         let mut recent: HashMap<String, DateTime<Utc>> = HashMap::new();
         let mut running = true;
+        let http = reqwest::Client::new();
 
 
         while running {
-            let mut local_client: Client = self.clone();
-
             tokio::time::sleep(self.delay.clone()).await;
 
+            let handler = self.event_handler.clone().unwrap();
 
-            let streamers: Vec<String> = local_client.config.streamers.clone();
+            if let Err(e) = self.ensure_token_fresh(&http).await {
+                handler.on_error(e).await;
+            }
+
+            let streamers: Vec<String> = self.config.streamers.clone();
 
             if streamers.is_empty() {
                 running = false;
@@ -235,35 +370,78 @@ impl Client {
 
                 recent.insert(streamer.clone(), Utc::now());
 
-                let handler = local_client.event_handler.clone().unwrap();
-                let t_string = local_client.token.clone();
-                let u_string = local_client.client_id.clone();
+                let handler = self.event_handler.clone().unwrap();
 
-                tokio::spawn(async move {
-                    let client = reqwest::Client::new();
+                let mut res = http.get(format!("https://api.twitch.tv/helix/streams?user_login={0}", streamer.clone()))
+                    .bearer_auth(self.token.clone()).header("Client-Id", self.client_id.clone()).send().await;
 
+                if let Ok(response) = &res {
+                    if response.status() == reqwest::StatusCode::UNAUTHORIZED && self.refresh_access_token(&http).await.is_ok() {
+                        res = http.get(format!("https://api.twitch.tv/helix/streams?user_login={0}", streamer.clone()))
+                            .bearer_auth(self.token.clone()).header("Client-Id", self.client_id.clone()).send().await;
+                    }
+                }
 
-                    let res = client.get(format!("https://api.twitch.tv/helix/streams?user_login={0}", streamer.clone()))
-                        .bearer_auth(t_string.clone()).header("Client-Id", u_string.clone()).send().await.expect("Error Occurred");
+                let res = res.expect("Error Occurred");
+                let streaming = self.currently_streaming.clone();
+                let viewer_threshold = self.viewer_threshold;
+                let nats_sink = self.nats_sink.clone();
+                let irc = self.irc.clone();
 
+                tokio::spawn(async move {
                     let rjson = res.json::<StreamsRes>().await;
 
                     match rjson {
-                        Ok(json) => unsafe {
-                            if json.data.is_empty() {
-                                return;
-                            }
-
-                            let info = json.data.first().expect("Missing Info");
-
-                            if C_STREAMING.contains(&info.user_id) {
-                                return;
+                        Ok(json) => {
+                            let mut streaming = streaming.lock().await;
+
+                            match json.data.into_iter().next() {
+                                Some(info) => {
+                                    let previous = streaming.insert(streamer.clone(), info.clone());
+                                    drop(streaming);
+
+                                    match previous {
+                                        None => {
+                                            handler.on_stream(&streamer, &info).await;
+
+                                            if let Some(sink) = &nats_sink {
+                                                if let Err(e) = sink.publish("stream.online", &streamer, Some(&info)).await {
+                                                    handler.on_error(e).await;
+                                                }
+                                            }
+
+                                            if let Some(irc) = &irc {
+                                                if let Err(e) = irc.announce(&info) {
+                                                    handler.on_error(e).await;
+                                                }
+                                            }
+                                        },
+                                        Some(previous) => {
+                                            let viewer_change = info.viewer_count.abs_diff(previous.viewer_count);
+
+                                            if previous.title != info.title || previous.game_id != info.game_id || viewer_change >= viewer_threshold {
+                                                handler.on_update(&streamer, &previous, &info).await;
+                                            }
+                                        }
+                                    }
+                                }
+                                None => {
+                                    let was_streaming = streaming.remove(&streamer).is_some();
+                                    drop(streaming);
+
+                                    if was_streaming {
+                                        handler.on_offline(&streamer).await;
+
+                                        if let Some(sink) = &nats_sink {
+                                            if let Err(e) = sink.publish("stream.offline", &streamer, None).await {
+                                                handler.on_error(e).await;
+                                            }
+                                        }
+                                    }
+                                }
                             }
-
-                            C_STREAMING.push(info.user_id.clone());
-                            handler.on_stream(&streamer, info).await;
                         },
-                        Err(e) => unsafe {
+                        Err(e) => {
                             if e.is_timeout() {
                                 handler.on_error(crate::error::Error::new("An error occurred due to timing out...", 1u16)).await;
                             } else if e.is_connect() {
@@ -279,9 +457,8 @@ impl Client {
                             } else if e.is_builder() {
                                 handler.on_error(crate::error::Error::new("An error occurred with the type builder...", 7u16)).await;
                             } else {
-                                if C_STREAMING.contains(&streamer) {
-                                    C_STREAMING.retain(|x | x.to_string() != streamer)
-                                }
+                                let mut streaming = streaming.lock().await;
+                                streaming.remove(&streamer);
                             }
                         }
                     }
@@ -293,4 +470,354 @@ impl Client {
         };
         Ok(())
     }
+
+    /// Used to start running the TwitchAlerts Client using Twitch EventSub over WebSocket
+    /// instead of polling `helix/streams` on a cycle. Subscribes to `stream.online` and
+    /// `stream.offline` for every configured streamer and reacts to push notifications,
+    /// removing the per-streamer request delay that [`Client::run`] pays every cycle.
+    ///
+    /// # Arguments
+    /// * `self` - Requires a Client To Run The Function
+    ///
+    /// # Example
+    /// ```no_run
+    /// use async_trait::async_trait;
+    /// use twitchalerts::client::{StreamData, Client};
+    /// use twitchalerts::traits::EventHandler;
+    ///
+    /// pub struct Handler;
+    ///
+    /// #[async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn on_stream(&self, streamer: &String, stream: &StreamData) {
+    ///         println!("{} Has Gone Live", streamer);
+    ///     }
+    /// }
+    ///
+    /// async fn main() -> Result<(), twitchalerts::error::Error> {
+    ///      _ = Client::new().await.event_handler(Handler).run_eventsub().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn run_eventsub(mut self) -> Result<(), crate::error::Error> {
+        if self.event_handler.is_none() {
+            panic!("No Event Handler Set");
+        }
+
+        let handler = self.event_handler.clone().unwrap();
+        let http = reqwest::Client::new();
+
+        self.ensure_token_fresh(&http).await?;
+
+        let broadcaster_ids = self.resolve_broadcaster_ids(&http, &handler).await?;
+
+        let mut ws_url = EVENTSUB_WS_URL.to_string();
+
+        let mut token_check_interval = tokio::time::interval(tokio::time::Duration::from_secs(TOKEN_CHECK_INTERVAL_SECS as u64));
+        token_check_interval.tick().await;
+
+        // Set once a `session_reconnect` hands us a migrated session, so the next connect skips
+        // `subscribe_all` instead of duplicating subscriptions Twitch already carried over.
+        let mut skip_subscribe = false;
+
+        let mut backoff = tokio::time::Duration::from_secs(1);
+
+        loop {
+            // Retries the connect -> session_welcome -> subscribe sequence with backoff instead
+            // of bubbling the first transient failure out of `run_eventsub` for good.
+            let (mut write, mut read, session) = loop {
+                let attempt = async {
+                    let (ws_stream, _) = connect_async(ws_url.as_str()).await
+                        .map_err(|_| crate::error::Error::new("An error occurred connecting to the EventSub WebSocket...", 12u16))?;
+
+                    let (write, mut read) = ws_stream.split();
+                    let session = self.read_session_welcome(&mut read).await?;
+
+                    if !skip_subscribe {
+                        self.subscribe_all(&http, &session.id, &broadcaster_ids).await?;
+                    }
+
+                    Ok::<_, crate::error::Error>((write, read, session))
+                }.await;
+
+                match attempt {
+                    Ok(result) => break result,
+                    Err(e) => {
+                        handler.on_error(e).await;
+                        tokio::time::sleep(backoff).await;
+                        backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+                    }
+                }
+            };
+
+            backoff = tokio::time::Duration::from_secs(1);
+
+            let keepalive_timeout = tokio::time::Duration::from_secs(session.keepalive_timeout_seconds.unwrap_or(10));
+            let mut watchdog = tokio::time::interval(keepalive_timeout);
+            watchdog.tick().await;
+
+            let mut reconnect_url: Option<String> = None;
+
+            loop {
+                tokio::select! {
+                    frame = read.next() => {
+                        let text = match frame {
+                            Some(Ok(Message::Text(text))) => text,
+                            Some(Ok(Message::Close(_))) | None => break,
+                            Some(Ok(_)) => continue,
+                            Some(Err(_)) => break
+                        };
+
+                        let parsed: EventSubMessage = match serde_json::from_str(&text) {
+                            Ok(parsed) => parsed,
+                            Err(_) => continue
+                        };
+
+                        watchdog.reset();
+
+                        match parsed.metadata.message_type.as_str() {
+                            "session_keepalive" => {}
+                            "session_reconnect" => {
+                                if let Ok(reconnect) = serde_json::from_value::<SessionPayload>(parsed.payload) {
+                                    reconnect_url = reconnect.session.reconnect_url;
+                                }
+                                let _ = write.close().await;
+                                break;
+                            }
+                            "notification" => {
+                                if let Ok(notification) = serde_json::from_value::<NotificationPayload>(parsed.payload) {
+                                    self.dispatch_notification(&http, &handler, notification).await;
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ = watchdog.tick() => {
+                        handler.on_error(crate::error::Error::new("EventSub WebSocket keepalive timed out, reconnecting...", 14u16)).await;
+                        break;
+                    }
+                    _ = token_check_interval.tick() => {
+                        if let Err(e) = self.ensure_token_fresh(&http).await {
+                            handler.on_error(e).await;
+                        }
+                    }
+                }
+            }
+
+            skip_subscribe = reconnect_url.is_some();
+
+            if let Some(url) = reconnect_url {
+                ws_url = url;
+            } else {
+                ws_url = EVENTSUB_WS_URL.to_string();
+            }
+        }
+    }
+
+    /// Reads Frames Off the EventSub WebSocket Until a `session_welcome` Arrives
+    async fn read_session_welcome(&self, read: &mut (impl futures_util::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin)) -> Result<Session, crate::error::Error> {
+        loop {
+            let msg = read.next().await
+                .ok_or_else(|| crate::error::Error::new("EventSub WebSocket closed before session_welcome...", 12u16))?
+                .map_err(|_| crate::error::Error::new("An error occurred reading the EventSub WebSocket...", 12u16))?;
+
+            if let Message::Text(text) = msg {
+                let parsed: EventSubMessage = serde_json::from_str(&text)
+                    .map_err(|_| crate::error::Error::new("An error occurred deserializing the session_welcome message...", 13u16))?;
+
+                if parsed.metadata.message_type == "session_welcome" {
+                    let welcome: SessionPayload = serde_json::from_value(parsed.payload)
+                        .map_err(|_| crate::error::Error::new("An error occurred deserializing the session_welcome payload...", 13u16))?;
+
+                    return Ok(welcome.session);
+                }
+            }
+        }
+    }
+
+    /// Resolves Every Configured `user_login` To Its Numeric Broadcaster ID via `helix/users`.
+    /// Any Configured Streamer `helix/users` Doesn't Return a Match For (Typo, Deleted/Banned
+    /// Account) Is Reported Through `handler.on_error` Instead of Being Silently Dropped.
+    async fn resolve_broadcaster_ids(&self, http: &reqwest::Client, handler: &Arc<dyn EventHandler>) -> Result<HashMap<String, String>, crate::error::Error> {
+        let mut ids = HashMap::new();
+
+        for streamer in self.config.streamers.clone() {
+            let res = http.get(format!("https://api.twitch.tv/helix/users?login={0}", streamer))
+                .bearer_auth(self.token.clone()).header("Client-Id", self.client_id.clone())
+                .send().await
+                .map_err(|_| crate::error::Error::new("An error occurred resolving a streamer's user id...", 15u16))?;
+
+            let users = res.json::<UsersRes>().await
+                .map_err(|_| crate::error::Error::new("An error occurred deserializing the helix/users response...", 15u16))?;
+
+            match users.data.first() {
+                Some(user) => {
+                    ids.insert(user.login.clone(), user.id.clone());
+                }
+                None => {
+                    handler.on_error(crate::error::Error::new(
+                        format!("helix/users returned no match for configured streamer \"{0}\", it will not be subscribed...", streamer).as_str(),
+                        26u16
+                    )).await;
+                }
+            }
+        }
+
+        Ok(ids)
+    }
+
+    /// Subscribes To `stream.online` and `stream.offline` For Every Resolved Broadcaster ID
+    async fn subscribe_all(&self, http: &reqwest::Client, session_id: &str, broadcaster_ids: &HashMap<String, String>) -> Result<(), crate::error::Error> {
+        for id in broadcaster_ids.values() {
+            for sub_type in ["stream.online", "stream.offline"] {
+                let body = SubscriptionRequest {
+                    sub_type: sub_type.to_string(),
+                    version: "1".to_string(),
+                    condition: SubscriptionCondition { broadcaster_user_id: id.clone() },
+                    transport: SubscriptionTransport { method: "websocket".to_string(), session_id: session_id.to_string() }
+                };
+
+                let res = http.post("https://api.twitch.tv/helix/eventsub/subscriptions")
+                    .bearer_auth(self.token.clone()).header("Client-Id", self.client_id.clone())
+                    .json(&body).send().await
+                    .map_err(|_| crate::error::Error::new("An error occurred creating an EventSub subscription...", 16u16))?;
+
+                if !res.status().is_success() {
+                    return Err(crate::error::Error::new("Twitch rejected an EventSub subscription request...", 25u16));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches a Decoded `notification` Frame To The `EventHandler`
+    async fn dispatch_notification(&self, http: &reqwest::Client, handler: &Arc<dyn EventHandler>, notification: NotificationPayload) {
+        match notification.subscription.sub_type.as_str() {
+            "stream.online" => {
+                let event = match serde_json::from_value::<StreamOnlineEvent>(notification.event) {
+                    Ok(event) => event,
+                    Err(_) => return
+                };
+
+                let res = http.get(format!("https://api.twitch.tv/helix/streams?user_id={0}", event.broadcaster_user_id))
+                    .bearer_auth(self.token.clone()).header("Client-Id", self.client_id.clone())
+                    .send().await;
+
+                let stream = match res {
+                    Ok(res) => match res.json::<StreamsRes>().await {
+                        Ok(json) => json.data.into_iter().next(),
+                        Err(_) => None
+                    },
+                    Err(_) => None
+                };
+
+                if let Some(stream) = stream {
+                    handler.on_stream(&event.broadcaster_user_login, &stream).await;
+
+                    if let Some(sink) = &self.nats_sink {
+                        if let Err(e) = sink.publish("stream.online", &event.broadcaster_user_login, Some(&stream)).await {
+                            handler.on_error(e).await;
+                        }
+                    }
+
+                    if let Some(irc) = &self.irc {
+                        if let Err(e) = irc.announce(&stream) {
+                            handler.on_error(e).await;
+                        }
+                    }
+                }
+            }
+            "stream.offline" => {
+                let event = match serde_json::from_value::<StreamOfflineEvent>(notification.event) {
+                    Ok(event) => event,
+                    Err(_) => return
+                };
+
+                handler.on_offline(&event.broadcaster_user_login).await;
+
+                if let Some(sink) = &self.nats_sink {
+                    if let Err(e) = sink.publish("stream.offline", &event.broadcaster_user_login, None).await {
+                        handler.on_error(e).await;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Calls `oauth2/validate` And Returns How Many Seconds Remain On The Current Token
+    async fn validate_token(&self, http: &reqwest::Client) -> Result<u64, crate::error::Error> {
+        let res = http.get("https://id.twitch.tv/oauth2/validate")
+            .header("Authorization", format!("OAuth {0}", self.token))
+            .send().await
+            .map_err(|_| crate::error::Error::new("An error occurred validating the token...", 17u16))?;
+
+        if !res.status().is_success() {
+            return Ok(0);
+        }
+
+        let validated = res.json::<ValidateRes>().await
+            .map_err(|_| crate::error::Error::new("An error occurred deserializing the oauth2/validate response...", 17u16))?;
+
+        Ok(validated.expires_in)
+    }
+
+    /// Refreshes The Token via `oauth2/token` And Persists The New Access/Refresh Pair
+    async fn refresh_access_token(&mut self, http: &reqwest::Client) -> Result<(), crate::error::Error> {
+        let refresh_token = self.refresh_token.clone().ok_or_else(|| crate::error::Error::new("No refresh_token configured, cannot refresh...", 18u16))?;
+        let client_secret = self.client_secret.clone().ok_or_else(|| crate::error::Error::new("No client_secret configured, cannot refresh...", 18u16))?;
+
+        let params = [
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token.as_str()),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", client_secret.as_str())
+        ];
+
+        let res = http.post("https://id.twitch.tv/oauth2/token")
+            .form(&params).send().await
+            .map_err(|_| crate::error::Error::new("An error occurred refreshing the token...", 18u16))?;
+
+        let refreshed = res.json::<RefreshRes>().await
+            .map_err(|_| crate::error::Error::new("An error occurred deserializing the oauth2/token response...", 18u16))?;
+
+        self.token = refreshed.access_token.clone();
+        self.refresh_token = Some(refreshed.refresh_token.clone());
+
+        if let Some(irc) = &self.irc {
+            irc.update_token(self.token.clone()).await;
+        }
+
+        let mut updated_config = self.config.clone();
+        updated_config.token = Some(refreshed.access_token);
+        updated_config.refresh_token = Some(refreshed.refresh_token);
+        self.config = updated_config.clone();
+
+        crate::config::write_config(updated_config).await;
+
+        Ok(())
+    }
+
+    /// Validates The Token And Refreshes It If It Is Near Expiry Or No Longer Valid. Throttled
+    /// to at most once every [`TOKEN_CHECK_INTERVAL_SECS`] so callers on a tight loop (e.g. the
+    /// minimum 80ms poll delay in [`Client::run`]) don't hammer `oauth2/validate`.
+    async fn ensure_token_fresh(&mut self, http: &reqwest::Client) -> Result<(), crate::error::Error> {
+        if let Some(last_checked) = self.last_token_check {
+            if (Utc::now() - last_checked).num_seconds() < TOKEN_CHECK_INTERVAL_SECS {
+                return Ok(());
+            }
+        }
+
+        self.last_token_check = Some(Utc::now());
+
+        let expires_in = self.validate_token(http).await.unwrap_or(0);
+
+        if expires_in < 600 {
+            self.refresh_access_token(http).await?;
+        }
+
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -8,7 +8,12 @@ pub(crate) struct Config {
     pub streamers: Vec<String>,
     pub delay: Option<u16>,
     pub token: Option<String>,
-    pub user_id: Option<String>
+    pub user_id: Option<String>,
+    pub client_secret: Option<String>,
+    pub refresh_token: Option<String>,
+    pub irc_nick: Option<String>,
+    pub irc_channels: Option<Vec<String>>,
+    pub irc_message_template: Option<String>
 }
 
 impl Default for Config {
@@ -17,7 +22,12 @@ impl Default for Config {
             streamers: Vec::new(),
             delay: Some(80u16),
             token: Some(String::new()),
-            user_id: Some(String::new())
+            user_id: Some(String::new()),
+            client_secret: None,
+            refresh_token: None,
+            irc_nick: None,
+            irc_channels: None,
+            irc_message_template: None
         }
     }
 }
@@ -54,7 +64,7 @@ pub(crate) async fn write_config(config: Config) {
     }
 
     let file = OpenOptions::new()
-        .read(true).write(true).open(env::current_exe().unwrap().parent().unwrap().join("/TwitchAlertsConfig.toml").as_path()).await;
+        .read(true).write(true).truncate(true).open(env::current_exe().unwrap().parent().unwrap().join("/TwitchAlertsConfig.toml").as_path()).await;
 
 
     let tconfig = toml::to_string(&config);
@@ -21,6 +21,29 @@ use std::fmt::{Debug, Display, Formatter};
 /// * `9` - A Tokio error occurred which resulted in a check being cancelled...
 /// * `10` - An error occurred causing the Tokio task to panic...
 /// * `11` - An unknown Tokio Error Occurred...
+///
+/// ### EventSub Codes
+/// * `12` - An error occurred connecting to or reading from the EventSub WebSocket...
+/// * `13` - An error occurred deserializing a session_welcome message...
+/// * `14` - The EventSub WebSocket keepalive timed out...
+/// * `15` - An error occurred resolving a streamer's user id...
+/// * `16` - An error occurred creating an EventSub subscription...
+/// * `25` - Twitch rejected an EventSub subscription request...
+/// * `26` - helix/users returned no match for a configured streamer...
+///
+/// ### Token Refresh Codes
+/// * `17` - An error occurred validating the token...
+/// * `18` - An error occurred refreshing the token...
+///
+/// ### NATS Sink Codes
+/// * `19` - An error occurred connecting to NATS...
+/// * `20` - An error occurred serializing a NATS alert event...
+/// * `21` - An error occurred publishing to NATS JetStream...
+/// * `24` - An error occurred confirming the NATS JetStream publish...
+///
+/// ### IRC Announcer Codes
+/// * `22` - No irc_nick or irc_channels configured, cannot start the IRC announcer...
+/// * `23` - An error occurred queuing an IRC announcement...
 /// # Example
 /// ```
 ///
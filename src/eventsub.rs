@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The Envelope Every EventSub WebSocket Frame Arrives In
+///
+/// # Parameters
+/// * `metadata` - Identifies the Frame's Type
+/// * `payload` - The Frame's Body, Shape Depends on `metadata.message_type`
+#[derive(Deserialize, Debug)]
+pub(crate) struct EventSubMessage {
+    pub metadata: Metadata,
+    pub payload: Value
+}
+
+/// Metadata Attached to Every EventSub WebSocket Frame
+///
+/// # Parameters
+/// * `message_type` - `session_welcome`, `session_keepalive`, `session_reconnect` or `notification`
+/// * `subscription_type` - The Subscription That Triggered a `notification` Frame
+#[derive(Deserialize, Debug)]
+pub(crate) struct Metadata {
+    pub message_type: String,
+    #[serde(default)]
+    pub subscription_type: Option<String>
+}
+
+/// The `payload` of a `session_welcome` or `session_reconnect` Frame
+///
+/// # Parameters
+/// * `session` - The Session Info Needed To Subscribe or Reconnect
+#[derive(Deserialize, Debug)]
+pub(crate) struct SessionPayload {
+    pub session: Session
+}
+
+/// The Session Info Twitch Hands Back Over the EventSub WebSocket
+///
+/// # Parameters
+/// * `id` - Session Identifier, Used as `transport.session_id` When Subscribing
+/// * `keepalive_timeout_seconds` - How Long To Wait Between Frames Before Reconnecting
+/// * `reconnect_url` - Where To Reconnect To, Present on `session_reconnect`
+#[derive(Deserialize, Debug)]
+pub(crate) struct Session {
+    pub id: String,
+    pub keepalive_timeout_seconds: Option<u64>,
+    pub reconnect_url: Option<String>
+}
+
+/// The `payload` of a `notification` Frame
+///
+/// # Parameters
+/// * `subscription` - The Subscription That Triggered The Notification
+/// * `event` - The Event Body, Shape Depends on `subscription.sub_type`
+#[derive(Deserialize, Debug)]
+pub(crate) struct NotificationPayload {
+    pub subscription: SubscriptionInfo,
+    pub event: Value
+}
+
+/// Identifies Which Subscription Triggered a `notification` Frame
+///
+/// # Parameters
+/// * `sub_type` - e.g. `stream.online` or `stream.offline`
+#[derive(Deserialize, Debug)]
+pub(crate) struct SubscriptionInfo {
+    #[serde(rename = "type")]
+    pub sub_type: String
+}
+
+/// The `event` Body of a `stream.online` Notification
+///
+/// # Parameters
+/// * `broadcaster_user_id` - Streamer's User ID
+/// * `broadcaster_user_login` - Streamer's User Login Name
+/// * `broadcaster_user_name` - Streamer's Username
+#[derive(Deserialize, Debug)]
+pub(crate) struct StreamOnlineEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String
+}
+
+/// The `event` Body of a `stream.offline` Notification
+///
+/// # Parameters
+/// * `broadcaster_user_id` - Streamer's User ID
+/// * `broadcaster_user_login` - Streamer's User Login Name
+/// * `broadcaster_user_name` - Streamer's Username
+#[derive(Deserialize, Debug)]
+pub(crate) struct StreamOfflineEvent {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String
+}
+
+/// The Response From `helix/users`
+#[derive(Deserialize, Debug)]
+pub(crate) struct UsersRes {
+    pub data: Vec<UserData>
+}
+
+/// A Single Entry in a `helix/users` Response
+///
+/// # Parameters
+/// * `id` - The User's Numeric ID
+/// * `login` - The User's Login Name
+#[derive(Deserialize, Debug)]
+pub(crate) struct UserData {
+    pub id: String,
+    pub login: String
+}
+
+/// The Body Sent to `helix/eventsub/subscriptions`
+#[derive(Serialize, Debug)]
+pub(crate) struct SubscriptionRequest {
+    #[serde(rename = "type")]
+    pub sub_type: String,
+    pub version: String,
+    pub condition: SubscriptionCondition,
+    pub transport: SubscriptionTransport
+}
+
+/// The `condition` Field of an EventSub Subscription Request
+#[derive(Serialize, Debug)]
+pub(crate) struct SubscriptionCondition {
+    pub broadcaster_user_id: String
+}
+
+/// The `transport` Field of an EventSub Subscription Request
+#[derive(Serialize, Debug)]
+pub(crate) struct SubscriptionTransport {
+    pub method: String,
+    pub session_id: String
+}
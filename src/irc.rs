@@ -0,0 +1,155 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task;
+use crate::client::StreamData;
+use crate::error::Error;
+
+/// Where The Twitch IRC Server Is Reached
+const IRC_HOST: &str = "irc.chat.twitch.tv:6667";
+
+/// Strips Control Characters (Including `\r`/`\n`) So Untrusted Values Can't Inject Extra IRC
+/// Commands Into a Raw `\r\n`-Terminated Line
+fn sanitize(value: &str) -> String {
+    value.chars().filter(|c| !c.is_control()).collect()
+}
+
+/// Announces `on_stream` Alerts Into Twitch Chat Over IRC, Independent of `EventHandler`
+///
+/// # Parameters
+/// * `sender` - Queues PRIVMSG Bodies For The Background Connection Task
+/// * `template` - The Message Template Substituted With `{name}`, `{title}`, `{game}` and `{url}`
+/// * `token` - The Live OAuth Token Shared With The Background Connection Task
+pub(crate) struct IrcAnnouncer {
+    sender: mpsc::UnboundedSender<String>,
+    template: String,
+    token: Arc<Mutex<String>>
+}
+
+impl IrcAnnouncer {
+    /// Connects To Twitch IRC And Spawns a Background Task That Owns The Connection,
+    /// Reconnecting Whenever It Drops And Answering `PING` With `PONG`
+    ///
+    /// # Arguments
+    /// * `token` - The OAuth Token Sent As The IRC `PASS`
+    /// * `nick` - The Nick Authenticated As via `NICK`
+    /// * `channels` - The Channels Joined on Connect And Announced Into
+    /// * `template` - The Message Template Substituted With `{name}`, `{title}`, `{game}` and `{url}`
+    pub fn connect(token: String, nick: String, channels: Vec<String>, template: String) -> IrcAnnouncer {
+        let (sender, receiver) = mpsc::unbounded_channel::<String>();
+        let token = Arc::new(Mutex::new(sanitize(&token)));
+        let nick = sanitize(&nick);
+        let channels: Vec<String> = channels.iter().map(|channel| sanitize(channel)).collect();
+
+        task::spawn(run_connection(token.clone(), nick, channels, receiver));
+
+        IrcAnnouncer { sender, template, token }
+    }
+
+    /// Updates The Token Used For Future `PASS` Lines, So a Refreshed Token (`chunk0-2`) Reaches
+    /// The Background Connection Task Instead of It Reconnecting With a Stale One
+    ///
+    /// # Arguments
+    /// * `self` - Requires an IrcAnnouncer To Run The Function
+    /// * `token` - The New OAuth Token To Authenticate With Going Forward
+    pub async fn update_token(&self, token: String) {
+        *self.token.lock().await = sanitize(&token);
+    }
+
+    /// Formats `template` With `stream`'s Data And Queues It As a PRIVMSG To Every Configured
+    /// `irc_channels` Entry (Not The Streamer's Own Channel, Which The Bot Never Joins)
+    ///
+    /// # Arguments
+    /// * `self` - Requires an IrcAnnouncer To Run The Function
+    /// * `stream` - The Stream's Data Used To Fill In The Template Placeholders
+    pub fn announce(&self, stream: &StreamData) -> Result<(), Error> {
+        let message = self.template
+            .replace("{name}", &sanitize(&stream.user_name))
+            .replace("{title}", &sanitize(&stream.title))
+            .replace("{game}", &sanitize(&stream.game_name))
+            .replace("{url}", &format!("https://twitch.tv/{0}", sanitize(&stream.user_login)));
+
+        self.sender.send(message)
+            .map_err(|_| Error::new("An error occurred queuing an IRC announcement...", 23u16))
+    }
+}
+
+/// Owns The IRC Connection, Reconnecting On Disconnect And Answering `PING` With `PONG`
+///
+/// # Arguments
+/// * `token` - The Live OAuth Token Sent As The IRC `PASS`, Re-read On Every (Re)Connect
+/// * `nick` - The Nick Authenticated As via `NICK`
+/// * `channels` - The Channels Joined on Connect And Announced Into
+/// * `receiver` - Receives Queued Messages, Sent As PRIVMSG To Every Joined Channel
+async fn run_connection(token: Arc<Mutex<String>>, nick: String, channels: Vec<String>, mut receiver: mpsc::UnboundedReceiver<String>) {
+    loop {
+        let stream = match TcpStream::connect(IRC_HOST).await {
+            Ok(stream) => stream,
+            Err(_) => {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                continue;
+            }
+        };
+
+        let (read_half, mut write_half) = stream.into_split();
+        let mut lines = BufReader::new(read_half).lines();
+
+        let current_token = token.lock().await.clone();
+
+        if write_half.write_all(format!("PASS oauth:{0}\r\n", current_token).as_bytes()).await.is_err() {
+            continue;
+        }
+        if write_half.write_all(format!("NICK {0}\r\n", nick).as_bytes()).await.is_err() {
+            continue;
+        }
+
+        let mut joined = true;
+
+        for channel in &channels {
+            if write_half.write_all(format!("JOIN #{0}\r\n", channel.trim_start_matches('#')).as_bytes()).await.is_err() {
+                joined = false;
+                break;
+            }
+        }
+
+        if !joined {
+            continue;
+        }
+
+        'connection: loop {
+            tokio::select! {
+                line = lines.next_line() => {
+                    match line {
+                        Ok(Some(line)) => {
+                            if line.starts_with("PING") {
+                                let reply = line.replacen("PING", "PONG", 1);
+                                if write_half.write_all(format!("{0}\r\n", reply).as_bytes()).await.is_err() {
+                                    break 'connection;
+                                }
+                            }
+                        }
+                        _ => break 'connection
+                    }
+                }
+                queued = receiver.recv() => {
+                    match queued {
+                        Some(message) => {
+                            for channel in &channels {
+                                let command = format!("PRIVMSG #{0} :{1}\r\n", channel.trim_start_matches('#'), message);
+
+                                if write_half.write_all(command.as_bytes()).await.is_err() {
+                                    break 'connection;
+                                }
+                            }
+                        }
+                        None => return
+                    }
+                }
+            }
+        }
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+    }
+}
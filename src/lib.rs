@@ -0,0 +1,7 @@
+pub mod client;
+mod config;
+pub mod error;
+pub mod traits;
+mod eventsub;
+mod nats_sink;
+mod irc;
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::client::StreamData;
+use crate::error::Error;
+
+/// A Single Event Published To a NATS JetStream Subject
+///
+/// # Parameters
+/// * `kind` - `stream.online` or `stream.offline`
+/// * `streamer` - The Twitch Streamer's Login Name
+/// * `stream` - The Stream's Data, Present For `stream.online`
+/// * `timestamp` - When The Event Was Published
+#[derive(Serialize, Debug)]
+pub(crate) struct AlertEvent<'a> {
+    pub kind: &'a str,
+    pub streamer: &'a str,
+    pub stream: Option<&'a StreamData>,
+    pub timestamp: DateTime<Utc>
+}
+
+/// Publishes Alert Events To a NATS JetStream Subject, Independent of `EventHandler`
+///
+/// # Parameters
+/// * `jetstream` - The JetStream Context Used To Publish
+/// * `subject` - The Subject Events Are Published To
+pub(crate) struct NatsSink {
+    jetstream: async_nats::jetstream::Context,
+    subject: String
+}
+
+impl NatsSink {
+    /// Connects To `url` And Returns a Sink That Publishes To `subject`
+    ///
+    /// # Arguments
+    /// * `url` - The NATS Server URL To Connect To
+    /// * `subject` - The JetStream Subject To Publish Alert Events To
+    pub async fn connect(url: &str, subject: &str) -> Result<NatsSink, Error> {
+        let client = async_nats::connect(url).await
+            .map_err(|_| Error::new("An error occurred connecting to NATS...", 19u16))?;
+
+        let jetstream = async_nats::jetstream::new(client);
+
+        Ok(NatsSink {
+            jetstream,
+            subject: subject.to_string()
+        })
+    }
+
+    /// Publishes a Stream Going Live or Offline To The Configured Subject
+    ///
+    /// # Arguments
+    /// * `self` - Requires a NatsSink To Run The Function
+    /// * `kind` - `stream.online` or `stream.offline`
+    /// * `streamer` - The Twitch Streamer's Login Name
+    /// * `stream` - The Stream's Data, Present For `stream.online`
+    pub async fn publish(&self, kind: &str, streamer: &str, stream: Option<&StreamData>) -> Result<(), Error> {
+        let event = AlertEvent {
+            kind,
+            streamer,
+            stream,
+            timestamp: Utc::now()
+        };
+
+        let body = serde_json::to_vec(&event)
+            .map_err(|_| Error::new("An error occurred serializing a NATS alert event...", 20u16))?;
+
+        let ack = self.jetstream.publish(self.subject.clone(), body.into()).await
+            .map_err(|_| Error::new("An error occurred publishing to NATS JetStream...", 21u16))?;
+
+        ack.await
+            .map_err(|_| Error::new("An error occurred confirming the NATS JetStream publish...", 24u16))?;
+
+        Ok(())
+    }
+}
@@ -6,24 +6,30 @@ use crate::error::Error;
 ///
 /// # Events
 /// * `on_stream` - The Event Triggered When a Streamer Goes Live
+/// * `on_offline` - The Event Triggered When a Streamer Who Was Live Goes Offline
+/// * `on_update` - The Event Triggered When a Live Streamer's Title or Game Changes, or Their Viewer Count Changes By At Least The Client's `viewer_threshold`
 /// * `on_error` - The Event Triggered When an Error Occurs
 ///
 /// # Example
 /// ```
 /// use async_trait::async_trait;
-/// use twitchalerts::client::{StreamData, Streamer};
+/// use twitchalerts::client::StreamData;
 /// use twitchalerts::traits::EventHandler;
 ///
 /// pub struct Handler;
 ///
 /// #[async_trait]
 /// impl EventHandler for Handler {
-///     async fn on_stream(&self, streamer: &Streamer, stream: &StreamData) {
-///         println!("{} Has Gone Live", streamer.name);
+///     async fn on_stream(&self, streamer: &String, stream: &StreamData) {
+///         println!("{} Has Gone Live", streamer);
 ///     }
 ///
-///     async fn on_error(&self, error: String) {
-///         println!("Error Occurred");
+///     async fn on_offline(&self, streamer: &str) {
+///         println!("{} Has Gone Offline", streamer);
+///     }
+///
+///     async fn on_update(&self, streamer: &str, old: &StreamData, new: &StreamData) {
+///         println!("{} Changed Title To {}", streamer, new.title);
 ///     }
 /// }
 /// ```
@@ -31,5 +37,7 @@ use crate::error::Error;
 #[async_trait]
 pub trait EventHandler: Send + Sync + 'static {
     async fn on_stream(&self, _streamer: &String, _stream: &StreamData) {}
+    async fn on_offline(&self, _streamer: &str) {}
+    async fn on_update(&self, _streamer: &str, _old: &StreamData, _new: &StreamData) {}
     async fn on_error(&self, _error: Error) {}
 }
\ No newline at end of file